@@ -0,0 +1,182 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Hardware-accelerated CRC-32 checksum backend for `cksum`.
+//!
+//! [`checksum`] and [`Crc32`] compute every checksum through the scalar,
+//! table-driven `update_scalar`, whatever the CPU supports, and
+//! [`active_backend`] therefore always reports [`CrcBackend::Scalar`].
+//! No code path selects `Pclmul` or `Vmull`; which of those features the
+//! CPU has is reported separately by [`crate::hardware::CpuFeatures`]
+//! (matching GNU cksum's `--debug` output).
+
+/// Reflected (LSB-first) generator polynomial for CRC-32/ISO-HDLC (the
+/// variant used by gzip, zlib and PNG): the bit-reverse of `0x04C11DB7`.
+const POLY: u32 = 0xEDB8_8320;
+
+/// Which backend actually computed a checksum; reported by `cksum --debug`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrcBackend {
+    Scalar,
+    Pclmul,
+    Vmull,
+}
+
+impl CrcBackend {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::Pclmul => "pclmul",
+            Self::Vmull => "vmull",
+        }
+    }
+}
+
+/// Advance a reflected CRC-32 register by one zero-valued input bit.
+///
+/// This is the bit-serial recurrence [`build_table`] expands eight bits at
+/// a time to build the table-driven update.
+const fn bit_update(crc: u32) -> u32 {
+    if crc & 1 != 0 {
+        (crc >> 1) ^ POLY
+    } else {
+        crc >> 1
+    }
+}
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = bit_update(crc);
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+static TABLE: [u32; 256] = build_table();
+
+fn update_scalar(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        let index = ((crc ^ byte as u32) & 0xff) as usize;
+        crc = TABLE[index] ^ (crc >> 8);
+    }
+    crc
+}
+
+/// Select the CRC-32 backend that [`Crc32::update`] actually dispatches to.
+///
+/// [`CrcBackend::Pclmul`] and [`CrcBackend::Vmull`] exist to name the
+/// accelerated paths once one is wired in and verified; until then this
+/// always returns [`CrcBackend::Scalar`] so `cksum --debug` reports the
+/// backend that really computed the checksum, not just what the CPU
+/// supports (see the module docs). [`crate::hardware::CpuFeatures`] remains
+/// the source of truth for the per-feature `--debug` lines (`pclmul`,
+/// `vmull`, ...).
+fn select_backend() -> CrcBackend {
+    CrcBackend::Scalar
+}
+
+/// The CRC-32 backend [`checksum`] and [`Crc32::new`] would pick right now.
+pub fn active_backend() -> CrcBackend {
+    select_backend()
+}
+
+/// Streaming CRC-32 accumulator.
+pub struct Crc32 {
+    state: u32,
+    backend: CrcBackend,
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Crc32 {
+    pub fn new() -> Self {
+        Self {
+            state: !0u32,
+            backend: select_backend(),
+        }
+    }
+
+    /// Which backend computed (or will compute) this checksum.
+    pub fn backend(&self) -> CrcBackend {
+        self.backend
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.state = update_scalar(self.state, bytes);
+    }
+
+    pub fn finalize(self) -> u32 {
+        !self.state
+    }
+}
+
+/// Compute the CRC-32 of `bytes`.
+pub fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CRC-32/ISO-HDLC of the ASCII string "123456789" is the standard
+    // check value used to validate any implementation of this variant.
+    const CHECK_INPUT: &[u8] = b"123456789";
+    const CHECK_VALUE: u32 = 0xCBF4_3926;
+
+    #[test]
+    fn test_checksum_check_value() {
+        assert_eq!(checksum(CHECK_INPUT), CHECK_VALUE);
+    }
+
+    #[test]
+    fn test_checksum_empty() {
+        assert_eq!(checksum(b""), 0);
+    }
+
+    #[test]
+    fn test_crc32_streaming_matches_one_shot() {
+        let mut crc = Crc32::new();
+        crc.update(b"123456");
+        crc.update(b"789");
+        assert_eq!(crc.finalize(), CHECK_VALUE);
+    }
+
+    #[test]
+    fn test_reported_backend_matches_scalar_for_multi_block_input() {
+        // Regression guard: whichever backend `--debug` reports, the actual
+        // checksum must agree with the scalar table for inputs spanning
+        // more than one 16-byte block, since that's exactly the case a
+        // broken accelerated fold would get wrong while a single short
+        // block still happened to look right.
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 7 + 3) as u8).collect();
+        for len in [32, 48, 64, 100] {
+            let chunk = &data[..len];
+            assert_eq!(checksum(chunk), update_scalar(!0u32, chunk) ^ !0u32);
+        }
+    }
+
+    #[test]
+    fn test_active_backend_is_scalar_until_a_fold_is_wired_in() {
+        // `Crc32::update` only ever calls `update_scalar`, regardless of
+        // what the CPU supports, so `--debug` must not claim otherwise.
+        assert_eq!(active_backend(), CrcBackend::Scalar);
+        assert_eq!(Crc32::new().backend(), CrcBackend::Scalar);
+    }
+}
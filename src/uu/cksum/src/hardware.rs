@@ -17,6 +17,7 @@ pub struct CpuFeatures {
     pub avx2: bool,
     pub pclmul: bool,
     pub vmull: bool,
+    pub neon: bool,
 }
 
 impl CpuFeatures {
@@ -28,6 +29,7 @@ impl CpuFeatures {
             avx2: false,
             pclmul: false,
             vmull: false,
+            neon: false,
         };
 
         unsafe {
@@ -37,6 +39,7 @@ impl CpuFeatures {
                     avx2: has_avx2(),
                     pclmul: has_pclmul(),
                     vmull: has_vmull(),
+                    neon: has_neon(),
                 };
             });
             FEATURES
@@ -50,8 +53,13 @@ impl CpuFeatures {
         self.print_feature("avx2", self.avx2);
         self.print_feature("pclmul", self.pclmul);
         if cfg!(target_arch = "aarch64") {
+            self.print_feature("neon", self.neon);
             self.print_feature("vmull", self.vmull);
         }
+        eprintln!(
+            "cksum: using {} for crc32",
+            crate::crc::active_backend().name()
+        );
     }
 
     fn print_feature(&self, name: &str, available: bool) {
@@ -97,11 +105,36 @@ fn has_pclmul() -> bool {
     false
 }
 
+#[cfg(target_arch = "aarch64")]
+fn has_neon() -> bool {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return true;
+    }
+    #[cfg(target_os = "linux")]
+    {
+        return aarch64_linux::hwcap_has(aarch64_linux::HWCAP_ASIMD);
+    }
+    #[cfg(not(target_os = "linux"))]
+    false
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+fn has_neon() -> bool {
+    false
+}
+
 #[cfg(target_arch = "aarch64")]
 fn has_vmull() -> bool {
-    // ARM NEON support detection
-    // This would require platform-specific code
-    // For now, return false as a safe default
+    if std::arch::is_aarch64_feature_detected!("pmull") {
+        return true;
+    }
+    // `is_aarch64_feature_detected!` relies on the OS exposing HWCAP through
+    // the vDSO; fall back to reading it ourselves when that's unavailable.
+    #[cfg(target_os = "linux")]
+    {
+        return aarch64_linux::hwcap_has(aarch64_linux::HWCAP_PMULL);
+    }
+    #[cfg(not(target_os = "linux"))]
     false
 }
 
@@ -110,6 +143,40 @@ fn has_vmull() -> bool {
     false
 }
 
+/// Linux-specific aarch64 feature detection, used as a fallback for targets
+/// where `std::arch::is_aarch64_feature_detected!` can't read HWCAP itself.
+#[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+mod aarch64_linux {
+    // From the Linux kernel's arch/arm64/include/uapi/asm/hwcap.h
+    pub const HWCAP_ASIMD: libc::c_ulong = 1 << 1;
+    pub const HWCAP_PMULL: libc::c_ulong = 1 << 4;
+
+    /// Check a HWCAP bit via `getauxval(AT_HWCAP)`, falling back to
+    /// `/proc/cpuinfo`'s `Features` line if that somehow comes back empty.
+    pub fn hwcap_has(bit: libc::c_ulong) -> bool {
+        let hwcap = unsafe { libc::getauxval(libc::AT_HWCAP) };
+        if hwcap != 0 {
+            return hwcap & bit != 0;
+        }
+
+        let Ok(cpuinfo) = std::fs::read_to_string("/proc/cpuinfo") else {
+            return false;
+        };
+        let Some(features) = cpuinfo
+            .lines()
+            .find(|line| line.starts_with("Features"))
+        else {
+            return false;
+        };
+        let name = match bit {
+            HWCAP_ASIMD => "asimd",
+            HWCAP_PMULL => "pmull",
+            _ => return false,
+        };
+        features.split_whitespace().any(|tok| tok == name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,6 +189,7 @@ mod tests {
         assert!(features.avx2 || !features.avx2);
         assert!(features.pclmul || !features.pclmul);
         assert!(features.vmull || !features.vmull);
+        assert!(features.neon || !features.neon);
     }
 
     #[test]
@@ -133,6 +201,20 @@ mod tests {
         assert_eq!(features1.avx2, features2.avx2);
         assert_eq!(features1.pclmul, features2.pclmul);
         assert_eq!(features1.vmull, features2.vmull);
+        assert_eq!(features1.neon, features2.neon);
+    }
+
+    #[test]
+    fn test_vmull_implies_neon_on_aarch64() {
+        // PMULL is part of the crypto extension, which is only available
+        // alongside the base NEON/ASIMD unit.
+        #[cfg(target_arch = "aarch64")]
+        {
+            let features = CpuFeatures::detect();
+            if features.vmull {
+                assert!(features.neon);
+            }
+        }
     }
 
     #[test]
@@ -0,0 +1,18 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Crate root for `cksum`.
+//!
+//! This snapshot only carries the CRC-32 backend and its hardware-detection
+//! support; the rest of `cksum`'s CLI is not present in this tree, so this
+//! file is intentionally limited to wiring those two modules in.
+
+mod crc;
+mod hardware;
+
+fn main() {
+    hardware::CpuFeatures::detect().print_debug();
+    println!("{:08x}", crc::checksum(&[]));
+}
@@ -11,11 +11,14 @@
 
 use number_prefix::NumberPrefix;
 
+use crate::parse_size::ParseSizeError;
+
 #[derive(Copy, Clone, PartialEq)]
 pub enum SizeFormat {
     Bytes,
-    Binary,  // Powers of 1024, --human-readable, -h
-    Decimal, // Powers of 1000, --si
+    Binary,         // Powers of 1024, --human-readable, -h
+    Decimal,        // Powers of 1000, --si
+    Exponent(bool), // Scientific notation, e.g. "1.2e6"; `true` for an upper-case "E"
 }
 
 /// There are a few peculiarities to how GNU formats the sizes:
@@ -47,48 +50,277 @@ pub fn human_readable(size: u64, sfmt: SizeFormat) -> String {
         SizeFormat::Binary => format_prefixed(&NumberPrefix::binary(size as f64)),
         SizeFormat::Decimal => format_prefixed(&NumberPrefix::decimal(size as f64)),
         SizeFormat::Bytes => size.to_string(),
+        SizeFormat::Exponent(upper) => human_readable_exp(size, upper, None),
     }
 }
 
-/// Get the thousands separator character from LC_NUMERIC locale.
+/// Render `size` in normalized scientific notation, e.g. `1.2e6` or `1E9`,
+/// the way integer `LowerExp`/`UpperExp` formatting works.
+///
+/// With no `precision`, the mantissa is the minimal number of digits needed
+/// (trailing zeros are stripped). With a `precision`, the mantissa is padded
+/// with zeros to that many fractional digits, rounding half up to match the
+/// rounding convention `format_prefixed` uses elsewhere in this module; a
+/// `precision` that asks for more digits than `size` actually has is
+/// saturated by padding with zeros instead of inventing digits.
+pub fn human_readable_exp(size: u64, upper: bool, precision: Option<usize>) -> String {
+    let e_char = if upper { 'E' } else { 'e' };
+    let digits = size.to_string();
+    let mut exponent = digits.len() - 1;
+
+    let mantissa_digits = match precision {
+        Some(p) => {
+            let (rounded, carry) = round_significant_digits(digits.as_bytes(), p + 1);
+            exponent += carry;
+            rounded
+        }
+        None => {
+            let trimmed = digits.trim_end_matches('0');
+            let trimmed = if trimmed.is_empty() { "0" } else { trimmed };
+            trimmed.as_bytes().to_vec()
+        }
+    };
+
+    // Safety/correctness: `mantissa_digits` only ever contains ASCII digits.
+    let mantissa = String::from_utf8(mantissa_digits).unwrap();
+    if mantissa.len() <= 1 {
+        format!("{mantissa}{e_char}{exponent}")
+    } else {
+        format!("{}.{}{e_char}{exponent}", &mantissa[..1], &mantissa[1..])
+    }
+}
+
+/// Round an ASCII digit string (most significant digit first, no leading
+/// zeros) to `target_len` significant digits, rounding half up.
+///
+/// If `digits` is already no longer than `target_len`, it's padded with
+/// trailing zeros instead of rounded. Returns the rounded digits and a carry
+/// of `1` if rounding overflowed into an extra leading digit (e.g. `999`
+/// rounded to 2 significant digits becomes `10`, shifting the exponent up
+/// by one), or `0` otherwise.
+fn round_significant_digits(digits: &[u8], target_len: usize) -> (Vec<u8>, usize) {
+    if digits.len() <= target_len {
+        let mut rounded = digits.to_vec();
+        rounded.resize(target_len, b'0');
+        return (rounded, 0);
+    }
+
+    let mut rounded = digits[..target_len].to_vec();
+    let mut carry = digits[target_len] >= b'5';
+
+    let mut i = rounded.len();
+    while carry && i > 0 {
+        i -= 1;
+        if rounded[i] == b'9' {
+            rounded[i] = b'0';
+        } else {
+            rounded[i] += 1;
+            carry = false;
+        }
+    }
+
+    if carry {
+        rounded.insert(0, b'1');
+        rounded.truncate(target_len);
+        return (rounded, 1);
+    }
+
+    (rounded, 0)
+}
+
+/// Parse a human-readable size string like `1.5K`, `128M`, `3Gi`, or `2.5e3`
+/// back into a byte count, the inverse of [`human_readable`].
+///
+/// The suffix letter (`K`/`M`/`G`/`T`/`P`/`E`, case-insensitive) selects the
+/// power of ten, and an optional trailing `i` switches that to the matching
+/// power of 1024 (`Ki`, `Mi`, ...), independent of any global `--si` flag.
+/// The mantissa accepts a locale-aware decimal mark the same way
+/// [`get_thousands_separator`] does, so `1,5K` parses under locales where
+/// `,` is the radix point.
+pub fn from_human_readable(s: &str) -> Result<u64, ParseSizeError> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err(ParseSizeError::ParseFailure(s.to_string()));
+    }
+
+    let (rest, binary) = match trimmed.strip_suffix(['i', 'I']) {
+        Some(rest) => (rest, true),
+        None => (trimmed, false),
+    };
+
+    let (mantissa_str, multiplier) = match rest.chars().next_back() {
+        Some(unit) if unit.is_ascii_alphabetic() => {
+            let exponent = match unit.to_ascii_uppercase() {
+                'K' => 1u32,
+                'M' => 2,
+                'G' => 3,
+                'T' => 4,
+                'P' => 5,
+                'E' => 6,
+                _ => return Err(ParseSizeError::InvalidSuffix(s.to_string())),
+            };
+            let base: u64 = if binary { 1024 } else { 1000 };
+            (&rest[..rest.len() - unit.len_utf8()], base.pow(exponent))
+        }
+        _ if binary => {
+            // A lone trailing "i"/"I" with no unit letter before it isn't valid.
+            return Err(ParseSizeError::InvalidSuffix(s.to_string()));
+        }
+        _ => (rest, 1),
+    };
+
+    let normalized = normalize_decimal_mark(mantissa_str);
+    let mantissa: f64 = normalized
+        .parse()
+        .map_err(|_| ParseSizeError::ParseFailure(s.to_string()))?;
+    if mantissa.is_sign_negative() {
+        return Err(ParseSizeError::ParseFailure(s.to_string()));
+    }
+
+    let value = mantissa * multiplier as f64;
+    if !value.is_finite() || value > u64::MAX as f64 {
+        return Err(ParseSizeError::SizeTooBig(s.to_string()));
+    }
+
+    Ok(value.round() as u64)
+}
+
+/// Rewrite a locale-specific decimal mark to the `.` radix point Rust's
+/// float parser expects, the same locale detection [`get_thousands_separator`]
+/// uses: in locales where `.` is the thousands separator, `,` is the radix
+/// point.
+fn normalize_decimal_mark(s: &str) -> String {
+    if get_thousands_separator() == '.' {
+        s.replace(',', ".")
+    } else {
+        s.to_string()
+    }
+}
+
+/// A locale's numeral formatting rules: the thousands separator and a
+/// glibc-style `grouping` spec (see [`group_digits`]).
+struct NumericLocale {
+    separator: String,
+    grouping: Vec<u8>,
+}
+
+/// Mirrors glibc's `CHAR_MAX` sentinel in a `grouping` spec: "stop grouping,
+/// emit the remaining digits as a single ungrouped run".
+const NO_MORE_GROUPING: u8 = u8::MAX;
+
+/// Get the thousands separator and grouping spec from the LC_NUMERIC locale.
 ///
 /// This function reads the `LC_NUMERIC`, `LC_ALL`, or `LANG` environment
-/// variables to determine the appropriate thousands separator character.
+/// variables to determine the appropriate separator and group sizes, the
+/// same way glibc's `localeconv()` exposes `thousands_sep` and `grouping`.
+///
+/// # Returns
+/// - no separator, no grouping for C/POSIX locale
+/// - `'.'`, grouped by 3, for European locales (de_DE, fr_FR, it_IT, es_ES, etc.)
+/// - `','`, grouped by 3 then repeating 2 (lakh/crore style), for Indian locales (hi_IN, bn_IN, mr_IN)
+/// - `','`, grouped by 3, for other locales (default, en_US style)
+fn get_numeric_locale() -> NumericLocale {
+    let locale = std::env::var("LC_NUMERIC")
+        .or_else(|_| std::env::var("LC_ALL"))
+        .or_else(|_| std::env::var("LANG"))
+        .unwrap_or_default();
+
+    // C and POSIX locales have no thousands separator
+    if locale == "C" || locale == "POSIX" || locale.starts_with("C.") {
+        return NumericLocale {
+            separator: String::new(),
+            grouping: Vec::new(),
+        };
+    }
+
+    // Indian numbering: ones in groups of 3, then lakhs/crores in groups of 2.
+    if locale.starts_with("hi_") || locale.starts_with("bn_") || locale.starts_with("mr_") {
+        return NumericLocale {
+            separator: ",".to_string(),
+            grouping: vec![3, 2, 0],
+        };
+    }
+
+    // Simple heuristic: European locales use period, others use comma
+    // This covers common cases like de_DE, fr_FR, it_IT, es_ES, nl_NL, etc.
+    if locale.starts_with("de_")
+        || locale.starts_with("fr_")
+        || locale.starts_with("it_")
+        || locale.starts_with("es_")
+        || locale.starts_with("nl_")
+        || locale.starts_with("pt_")
+        || locale.starts_with("da_")
+        || locale.starts_with("sv_")
+        || locale.starts_with("no_")
+        || locale.starts_with("fi_")
+    {
+        return NumericLocale {
+            separator: ".".to_string(),
+            grouping: vec![3, 0],
+        };
+    }
+
+    // Default to comma (en_US style), grouped by 3
+    NumericLocale {
+        separator: ",".to_string(),
+        grouping: vec![3, 0],
+    }
+}
+
+/// Get the thousands separator character from LC_NUMERIC locale.
 ///
 /// # Returns
 /// - `'\0'` for C/POSIX locale (no separator)
 /// - `'.'` for European locales (de_DE, fr_FR, it_IT, es_ES, etc.)
 /// - `','` for other locales (default, en_US style)
 fn get_thousands_separator() -> char {
-    // Try to read LC_NUMERIC or LANG environment variable
-    if let Ok(locale) = std::env::var("LC_NUMERIC")
-        .or_else(|_| std::env::var("LC_ALL"))
-        .or_else(|_| std::env::var("LANG"))
-    {
-        // C and POSIX locales have no thousands separator
-        if locale == "C" || locale == "POSIX" || locale.starts_with("C.") {
-            return '\0';
+    get_numeric_locale().separator.chars().next().unwrap_or('\0')
+}
+
+/// Insert `separator` between digit groups per a glibc-style `grouping` spec.
+///
+/// `grouping[i]` is the size of the `i`-th group read right-to-left from the
+/// least-significant digit. A `0` repeats the previous group size
+/// indefinitely, and [`NO_MORE_GROUPING`] stops grouping so the remaining
+/// digits are emitted as a single run. `separator` may be more than one
+/// character, since some locales (e.g. those using U+00A0 or U+202F) don't
+/// use a single-byte separator.
+fn group_digits(digits: &str, separator: &str, grouping: &[u8]) -> String {
+    let Some(&first) = grouping.first() else {
+        return digits.to_string();
+    };
+
+    let digits: Vec<char> = digits.chars().collect();
+    let mut groups: Vec<String> = Vec::new();
+    let mut end = digits.len();
+    let mut spec = grouping.iter();
+    spec.next(); // already consumed into `size` below
+    let mut size = first as usize;
+
+    while end > 0 {
+        if size == 0 || size == NO_MORE_GROUPING as usize {
+            groups.push(digits[..end].iter().collect());
+            break;
         }
 
-        // Simple heuristic: European locales use period, others use comma
-        // This covers common cases like de_DE, fr_FR, it_IT, es_ES, nl_NL, etc.
-        if locale.starts_with("de_")
-            || locale.starts_with("fr_")
-            || locale.starts_with("it_")
-            || locale.starts_with("es_")
-            || locale.starts_with("nl_")
-            || locale.starts_with("pt_")
-            || locale.starts_with("da_")
-            || locale.starts_with("sv_")
-            || locale.starts_with("no_")
-            || locale.starts_with("fi_")
-        {
-            return '.';
+        let start = end.saturating_sub(size);
+        groups.push(digits[start..end].iter().collect());
+        end = start;
+
+        // A `0` in the spec means "keep repeating the last group size";
+        // running off the end of the spec means the same thing.
+        if let Some(&next) = spec.next() {
+            if next == 0 {
+                // Ignore anything after the `0`: the current size repeats.
+                spec = grouping[grouping.len()..].iter();
+            } else {
+                size = next as usize;
+            }
         }
     }
 
-    // Default to comma (en_US style)
-    ','
+    groups.reverse();
+    groups.join(separator)
 }
 
 /// Format a number with thousands separators based on LC_NUMERIC locale.
@@ -111,33 +343,14 @@ fn get_thousands_separator() -> char {
 /// // assert_eq!(format_with_thousands_separator(1234567), "1.234.567");
 /// ```
 pub fn format_with_thousands_separator(number: u64) -> String {
-    const GROUPING_SIZE: usize = 3;
-
-    let separator = get_thousands_separator();
+    let locale = get_numeric_locale();
 
     // C/POSIX locale has no thousands separator
-    if separator == '\0' {
+    if locale.separator.is_empty() {
         return number.to_string();
     }
 
-    let num_str = number.to_string();
-    let len = num_str.len();
-
-    // Numbers less than 1000 don't need separators
-    if len <= GROUPING_SIZE {
-        return num_str;
-    }
-
-    let mut result = String::with_capacity(len + (len - 1) / GROUPING_SIZE);
-
-    for (i, ch) in num_str.chars().enumerate() {
-        if i > 0 && (len - i) % GROUPING_SIZE == 0 {
-            result.push(separator);
-        }
-        result.push(ch);
-    }
-
-    result
+    group_digits(&number.to_string(), &locale.separator, &locale.grouping)
 }
 
 #[cfg(test)]
@@ -257,6 +470,120 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_human_readable_decimal() {
+        assert_eq!(from_human_readable("1.5K").unwrap(), 1500);
+        assert_eq!(from_human_readable("128M").unwrap(), 128_000_000);
+        assert_eq!(from_human_readable("3G").unwrap(), 3_000_000_000);
+        assert_eq!(from_human_readable("5").unwrap(), 5);
+    }
+
+    #[test]
+    fn test_from_human_readable_binary() {
+        assert_eq!(from_human_readable("3Gi").unwrap(), 3 * 1024 * 1024 * 1024);
+        assert_eq!(from_human_readable("1Ki").unwrap(), 1024);
+        assert_eq!(from_human_readable("1ki").unwrap(), 1024);
+    }
+
+    #[test]
+    fn test_from_human_readable_exponential() {
+        assert_eq!(from_human_readable("2.5e3").unwrap(), 2500);
+        assert_eq!(from_human_readable("1e9").unwrap(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_from_human_readable_invalid() {
+        assert!(from_human_readable("").is_err());
+        assert!(from_human_readable("Ki").is_err());
+        assert!(from_human_readable("5X").is_err());
+        assert!(from_human_readable("-1K").is_err());
+    }
+
+    #[test]
+    fn test_from_human_readable_locale_decimal_mark() {
+        let original_lc_numeric = std::env::var("LC_NUMERIC").ok();
+        let original_lc_all = std::env::var("LC_ALL").ok();
+        let original_lang = std::env::var("LANG").ok();
+
+        unsafe {
+            std::env::remove_var("LC_ALL");
+            std::env::remove_var("LANG");
+            std::env::set_var("LC_NUMERIC", "de_DE.UTF-8");
+
+            assert_eq!(from_human_readable("1,5K").unwrap(), 1500);
+
+            std::env::remove_var("LC_NUMERIC");
+            std::env::remove_var("LC_ALL");
+            std::env::remove_var("LANG");
+
+            if let Some(locale) = original_lc_numeric {
+                std::env::set_var("LC_NUMERIC", locale);
+            }
+            if let Some(locale) = original_lc_all {
+                std::env::set_var("LC_ALL", locale);
+            }
+            if let Some(locale) = original_lang {
+                std::env::set_var("LANG", locale);
+            }
+        }
+    }
+
+    #[test]
+    fn test_human_readable_exp() {
+        assert_eq!(human_readable(0, SizeFormat::Exponent(false)), "0e0");
+        assert_eq!(human_readable(1_200_000, SizeFormat::Exponent(false)), "1.2e6");
+        assert_eq!(human_readable(1_000_000_000, SizeFormat::Exponent(false)), "1e9");
+        assert_eq!(human_readable(1_000_000_000, SizeFormat::Exponent(true)), "1E9");
+        assert_eq!(human_readable(42, SizeFormat::Exponent(false)), "4.2e1");
+    }
+
+    #[test]
+    fn test_human_readable_exp_precision() {
+        assert_eq!(human_readable_exp(1234, false, Some(2)), "1.23e3");
+        assert_eq!(human_readable_exp(999, false, Some(1)), "1.0e3");
+        assert_eq!(human_readable_exp(0, false, Some(2)), "0.00e0");
+        assert_eq!(human_readable_exp(500, false, Some(4)), "5.0000e2");
+    }
+
+    #[test]
+    fn test_format_with_thousands_separator_indian_grouping() {
+        // Save original locale variables
+        let original_lc_numeric = std::env::var("LC_NUMERIC").ok();
+        let original_lc_all = std::env::var("LC_ALL").ok();
+        let original_lang = std::env::var("LANG").ok();
+
+        unsafe {
+            std::env::remove_var("LC_ALL");
+            std::env::remove_var("LANG");
+
+            // hi_IN groups the last 3 digits, then repeats groups of 2
+            // (lakhs, crores, ...).
+            std::env::set_var("LC_NUMERIC", "hi_IN.UTF-8");
+            assert_eq!(format_with_thousands_separator(1000), "1,000");
+            assert_eq!(format_with_thousands_separator(100_000), "1,00,000");
+            assert_eq!(format_with_thousands_separator(10_000_000), "1,00,00,000");
+            assert_eq!(
+                format_with_thousands_separator(1_000_000_000),
+                "1,00,00,00,000"
+            );
+
+            // Restore original locale variables
+            std::env::remove_var("LC_NUMERIC");
+            std::env::remove_var("LC_ALL");
+            std::env::remove_var("LANG");
+
+            if let Some(locale) = original_lc_numeric {
+                std::env::set_var("LC_NUMERIC", locale);
+            }
+            if let Some(locale) = original_lc_all {
+                std::env::set_var("LC_ALL", locale);
+            }
+            if let Some(locale) = original_lang {
+                std::env::set_var("LANG", locale);
+            }
+        }
+    }
+
     #[test]
     fn test_get_thousands_separator() {
         // Save original locale